@@ -1,10 +1,49 @@
 use super::*;
 use std::fmt;
+use std::iter;
+use std::marker::PhantomData;
 use std::mem;
+use std::slice;
 use std::sync::{Mutex, MutexGuard};
 
+/// Default per-section extra data, the color/depth pair previously hardcoded onto
+/// `GlyphedSection`. Used as the default `X` type by
+/// [`GlyphCalculator`](struct.GlyphCalculator.html)/[`GlyphCalculatorBuilder`](struct.GlyphCalculatorBuilder.html)
+/// so existing code that doesn't care about a custom extra type keeps compiling.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Extra {
+    pub color: Color,
+    pub z: f32,
+}
+
+impl Default for Extra {
+    fn default() -> Self {
+        Extra {
+            color: [0.0, 0.0, 0.0, 1.0],
+            z: 0.0,
+        }
+    }
+}
+
+impl Hash for Extra {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let Extra { color, z } = self;
+        for c in color {
+            c.to_bits().hash(state);
+        }
+        z.to_bits().hash(state);
+    }
+}
+
+/// Iterator over a section's positioned glyphs paired with their extra data, see
+/// [`GlyphCruncher::glyphs_and_extras`](trait.GlyphCruncher.html#method.glyphs_and_extras).
+pub type GlyphExtraIter<'a, 'font, X> = iter::Map<
+    slice::Iter<'a, (PositionedGlyph<'font>, X, FontId)>,
+    fn(&'a (PositionedGlyph<'font>, X, FontId)) -> (&'a PositionedGlyph<'font>, &'a X),
+>;
+
 /// Common glyph layout logic.
-pub trait GlyphCruncher<'font> {
+pub trait GlyphCruncher<'font, X = Extra> {
     /// Returns the pixel bounding box for the input section using a custom layout.
     /// The box is a conservative whole number pixel rectangle that can contain the section.
     ///
@@ -35,6 +74,37 @@ pub trait GlyphCruncher<'font> {
         self.pixel_bounds_custom_layout(section, &layout)
     }
 
+    /// Returns the exact, unrounded pixel bounding box for the input section using a custom
+    /// layout. Unlike [`pixel_bounds_custom_layout`](#method.pixel_bounds_custom_layout) the
+    /// returned rect is not ceiled/floored to whole pixels, which is useful when the caller needs
+    /// to measure a section to lay it out rather than to draw it.
+    ///
+    /// If the section is empty or would result in no drawn glyphs will return `None`
+    ///
+    /// Benefits from caching, see [caching behaviour](#caching-behaviour).
+    fn glyph_bounds_custom_layout<'a, S, L>(
+        &mut self,
+        section: S,
+        custom_layout: &L,
+    ) -> Option<Rect<f32>>
+    where
+        L: GlyphPositioner + Hash,
+        S: Into<Cow<'a, VariedSection<'a>>>;
+
+    /// Returns the exact, unrounded pixel bounding box for the input section.
+    ///
+    /// If the section is empty or would result in no drawn glyphs will return `None`
+    ///
+    /// Benefits from caching, see [caching behaviour](#caching-behaviour).
+    fn glyph_bounds<'a, S>(&mut self, section: S) -> Option<Rect<f32>>
+    where
+        S: Into<Cow<'a, VariedSection<'a>>>,
+    {
+        let section = section.into();
+        let layout = section.layout;
+        self.glyph_bounds_custom_layout(section, &layout)
+    }
+
     /// Returns an iterator over the `PositionedGlyph`s of the given section with a custom layout.
     ///
     /// Benefits from caching, see [caching behaviour](#caching-behaviour).
@@ -58,6 +128,32 @@ pub trait GlyphCruncher<'font> {
         let layout = section.layout;
         self.glyphs_custom_layout(section, &layout)
     }
+
+    /// Returns an iterator over the `PositionedGlyph`s of the given section with a custom layout,
+    /// paired with the `X` extra data stored alongside each glyph.
+    ///
+    /// Benefits from caching, see [caching behaviour](#caching-behaviour).
+    fn glyphs_and_extras_custom_layout<'a, 'b, S, L>(
+        &'b mut self,
+        section: S,
+        custom_layout: &L,
+    ) -> GlyphExtraIter<'b, 'font, X>
+    where
+        L: GlyphPositioner + Hash,
+        S: Into<Cow<'a, VariedSection<'a>>>;
+
+    /// Returns an iterator over the `PositionedGlyph`s of the given section, paired with the `X`
+    /// extra data stored alongside each glyph.
+    ///
+    /// Benefits from caching, see [caching behaviour](#caching-behaviour).
+    fn glyphs_and_extras<'a, 'b, S>(&'b mut self, section: S) -> GlyphExtraIter<'b, 'font, X>
+    where
+        S: Into<Cow<'a, VariedSection<'a>>>,
+    {
+        let section = section.into();
+        let layout = section.layout;
+        self.glyphs_and_extras_custom_layout(section, &layout)
+    }
 }
 
 /// Cut down version of a [`GlyphBrush`](struct.GlyphBrush.html) that can calculate pixel bounds,
@@ -105,24 +201,31 @@ pub trait GlyphCruncher<'font> {
 /// is created, that provides the calculation functionality. Dropping indicates the 'cache frame'
 /// is over, similar to when a `GlyphBrush` draws. Section calculations are cached for the next
 /// 'cache frame', if not used then they will be dropped.
-pub struct GlyphCalculator<'font, H = DefaultSectionHasher> {
+pub struct GlyphCalculator<'font, H = DefaultSectionHasher, X = Extra> {
     fonts: FontMap<'font>,
 
     // cache of section-layout hash -> computed glyphs, this avoid repeated glyph computation
     // for identical layout/sections common to repeated frame rendering
-    calculate_glyph_cache: Mutex<FxHashMap<u64, GlyphedSection<'font>>>,
+    calculate_glyph_cache: Mutex<FxHashMap<u64, GlyphedSection<'font, X>>>,
 
     section_hasher: H,
 }
 
-impl<'font, H> fmt::Debug for GlyphCalculator<'font, H> {
+impl<'font, H, X> fmt::Debug for GlyphCalculator<'font, H, X> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "GlyphCalculator")
     }
 }
 
-impl<'font, H: BuildHasher + Clone> GlyphCalculator<'font, H> {
-    pub fn cache_scope<'a>(&'a self) -> GlyphCalculatorGuard<'a, 'font, H> {
+impl<'font, H, X> GlyphCalculator<'font, H, X> {
+    /// Returns the fonts held by this calculator, ordered by their `FontId`.
+    pub fn fonts(&self) -> &[Font<'font>] {
+        self.fonts.fonts()
+    }
+}
+
+impl<'font, H: BuildHasher + Clone, X> GlyphCalculator<'font, H, X> {
+    pub fn cache_scope<'a>(&'a self) -> GlyphCalculatorGuard<'a, 'font, H, X> {
         GlyphCalculatorGuard {
             fonts: &self.fonts,
             glyph_cache: self.calculate_glyph_cache.lock().unwrap(),
@@ -133,15 +236,21 @@ impl<'font, H: BuildHasher + Clone> GlyphCalculator<'font, H> {
 }
 
 /// [`GlyphCalculator`](struct.GlyphCalculator.html) scoped cache lock.
-pub struct GlyphCalculatorGuard<'brush, 'font: 'brush, H = DefaultSectionHasher> {
+pub struct GlyphCalculatorGuard<'brush, 'font: 'brush, H = DefaultSectionHasher, X = Extra> {
     fonts: &'brush FontMap<'font>,
-    glyph_cache: MutexGuard<'brush, FxHashMap<u64, GlyphedSection<'font>>>,
+    glyph_cache: MutexGuard<'brush, FxHashMap<u64, GlyphedSection<'font, X>>>,
     cached: FxHashSet<u64>,
     section_hasher: H,
 }
 
-impl<'brush, 'font, H: BuildHasher> GlyphCalculatorGuard<'brush, 'font, H> {
-    /// Returns the calculate_glyph_cache key for this sections glyphs
+impl<'brush, 'font, H: BuildHasher, X> GlyphCalculatorGuard<'brush, 'font, H, X>
+where
+    X: Clone + From<Extra>,
+{
+    /// Returns the calculate_glyph_cache key for this section's glyphs, keyed on the section
+    /// and layout. The per-glyph `X` is derived from each glyph's real computed color and the
+    /// section's `z`, so it doesn't need to be folded into the key separately: it is already a
+    /// pure function of data the section/layout hash covers.
     fn cache_glyphs<L>(&mut self, section: &VariedSection, layout: &L) -> u64
     where
         L: GlyphPositioner,
@@ -154,25 +263,68 @@ impl<'brush, 'font, H: BuildHasher> GlyphCalculatorGuard<'brush, 'font, H> {
         };
 
         if let Entry::Vacant(entry) = self.glyph_cache.entry(section_hash) {
+            let glyphs = layout
+                .calculate_glyphs(self.fonts, section)
+                .into_iter()
+                .map(|(glyph, color, font_id)| {
+                    let extra = X::from(Extra { color, z: section.z });
+                    (glyph, extra, font_id)
+                })
+                .collect();
+
             entry.insert(GlyphedSection {
                 bounds: layout.bounds_rect(section),
-                glyphs: layout.calculate_glyphs(self.fonts, section),
-                z: section.z,
+                glyphs,
             });
         }
 
         section_hash
     }
+
+    /// Calculates glyphs for a section with a custom layout and keeps the result cached for the
+    /// next 'cache frame', without returning anything. This is useful when the caller knows a
+    /// section will be measured again next frame and wants to avoid the section dropping out of
+    /// the cache and being recomputed.
+    pub fn keep_cached_custom_layout<'a, S, L>(&mut self, section: S, custom_layout: &L)
+    where
+        L: GlyphPositioner + Hash,
+        S: Into<Cow<'a, VariedSection<'a>>>,
+    {
+        let section_hash = self.cache_glyphs(&section.into(), custom_layout);
+        self.cached.insert(section_hash);
+    }
+
+    /// Calculates glyphs for a section and keeps the result cached for the next 'cache frame',
+    /// without returning anything, see
+    /// [`keep_cached_custom_layout`](#method.keep_cached_custom_layout).
+    pub fn keep_cached<'a, S>(&mut self, section: S)
+    where
+        S: Into<Cow<'a, VariedSection<'a>>>,
+    {
+        let section = section.into();
+        let layout = section.layout;
+        self.keep_cached_custom_layout(section, &layout);
+    }
+}
+
+impl<'brush, 'font, H, X> GlyphCalculatorGuard<'brush, 'font, H, X> {
+    /// Returns the fonts held by the calculator this guard was scoped from, ordered by their
+    /// `FontId`.
+    pub fn fonts(&self) -> &[Font<'font>] {
+        self.fonts.fonts()
+    }
 }
 
-impl<'brush, 'font> fmt::Debug for GlyphCalculatorGuard<'brush, 'font> {
+impl<'brush, 'font, H, X> fmt::Debug for GlyphCalculatorGuard<'brush, 'font, H, X> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "GlyphCalculatorGuard")
     }
 }
 
-impl<'brush, 'font, H: BuildHasher> GlyphCruncher<'font>
-    for GlyphCalculatorGuard<'brush, 'font, H>
+impl<'brush, 'font, H: BuildHasher, X> GlyphCruncher<'font, X>
+    for GlyphCalculatorGuard<'brush, 'font, H, X>
+where
+    X: Clone + From<Extra>,
 {
     fn pixel_bounds_custom_layout<'a, S, L>(
         &mut self,
@@ -188,6 +340,20 @@ impl<'brush, 'font, H: BuildHasher> GlyphCruncher<'font>
         self.glyph_cache[&section_hash].pixel_bounds()
     }
 
+    fn glyph_bounds_custom_layout<'a, S, L>(
+        &mut self,
+        section: S,
+        custom_layout: &L,
+    ) -> Option<Rect<f32>>
+    where
+        L: GlyphPositioner + Hash,
+        S: Into<Cow<'a, VariedSection<'a>>>,
+    {
+        let section_hash = self.cache_glyphs(&section.into(), custom_layout);
+        self.cached.insert(section_hash);
+        self.glyph_cache[&section_hash].glyph_bounds()
+    }
+
     fn glyphs_custom_layout<'a, 'b, S, L>(
         &'b mut self,
         section: S,
@@ -201,9 +367,23 @@ impl<'brush, 'font, H: BuildHasher> GlyphCruncher<'font>
         self.cached.insert(section_hash);
         self.glyph_cache[&section_hash].glyphs()
     }
+
+    fn glyphs_and_extras_custom_layout<'a, 'b, S, L>(
+        &'b mut self,
+        section: S,
+        custom_layout: &L,
+    ) -> GlyphExtraIter<'b, 'font, X>
+    where
+        L: GlyphPositioner + Hash,
+        S: Into<Cow<'a, VariedSection<'a>>>,
+    {
+        let section_hash = self.cache_glyphs(&section.into(), custom_layout);
+        self.cached.insert(section_hash);
+        self.glyph_cache[&section_hash].glyphs_and_extras()
+    }
 }
 
-impl<'a, 'b, H> Drop for GlyphCalculatorGuard<'a, 'b, H> {
+impl<'a, 'b, H, X> Drop for GlyphCalculatorGuard<'a, 'b, H, X> {
     fn drop(&mut self) {
         let cached = mem::replace(&mut self.cached, HashSet::default());
         self.glyph_cache.retain(|key, _| cached.contains(key));
@@ -227,9 +407,10 @@ impl<'a, 'b, H> Drop for GlyphCalculatorGuard<'a, 'b, H> {
 /// # let _ = glyphs;
 /// # }
 /// ```
-pub struct GlyphCalculatorBuilder<'a, H = DefaultSectionHasher> {
+pub struct GlyphCalculatorBuilder<'a, H = DefaultSectionHasher, X = Extra> {
     font_data: Vec<Font<'a>>,
     section_hasher: H,
+    extra: PhantomData<X>,
 }
 
 impl<'a> GlyphCalculatorBuilder<'a> {
@@ -263,11 +444,20 @@ impl<'a> GlyphCalculatorBuilder<'a> {
         Self {
             font_data: fonts.into(),
             section_hasher: DefaultSectionHasher::default(),
+            extra: PhantomData,
         }
     }
+
+    /// Creates a builder with no fonts. Fonts must be added with
+    /// [`add_font`](#method.add_font)/[`add_font_bytes`](#method.add_font_bytes) before
+    /// [`build`](#method.build) is useful, as a `GlyphCalculator` with no fonts cannot position
+    /// any glyphs.
+    pub fn without_fonts() -> Self {
+        Self::using_fonts(Vec::<Font<'a>>::new())
+    }
 }
 
-impl<'a, H: BuildHasher> GlyphCalculatorBuilder<'a, H> {
+impl<'a, H: BuildHasher, X> GlyphCalculatorBuilder<'a, H, X> {
     /// Adds additional fonts to the one added in [`using_font`](#method.using_font) /
     /// [`using_font_bytes`](#method.using_font_bytes).
     ///
@@ -296,15 +486,27 @@ impl<'a, H: BuildHasher> GlyphCalculatorBuilder<'a, H> {
     pub fn section_hasher<T: BuildHasher>(
         self,
         section_hasher: T,
-    ) -> GlyphCalculatorBuilder<'a, T> {
+    ) -> GlyphCalculatorBuilder<'a, T, X> {
         GlyphCalculatorBuilder {
             font_data: self.font_data,
             section_hasher,
+            extra: PhantomData,
+        }
+    }
+
+    /// Sets the extra data type stored alongside each positioned glyph, see
+    /// [`GlyphCruncher::glyphs_and_extras`](trait.GlyphCruncher.html#method.glyphs_and_extras). Defaults to
+    /// [`Extra`](struct.Extra.html), the built-in color/depth pair.
+    pub fn extra_type<Y>(self) -> GlyphCalculatorBuilder<'a, H, Y> {
+        GlyphCalculatorBuilder {
+            font_data: self.font_data,
+            section_hasher: self.section_hasher,
+            extra: PhantomData,
         }
     }
 
     /// Builds a `GlyphCalculator`
-    pub fn build(self) -> GlyphCalculator<'a, H> {
+    pub fn build(self) -> GlyphCalculator<'a, H, X> {
         let fonts = {
             let mut fonts = FontMap::with_capacity(self.font_data.len());
             for (idx, data) in self.font_data.into_iter().enumerate() {
@@ -322,13 +524,12 @@ impl<'a, H: BuildHasher> GlyphCalculatorBuilder<'a, H> {
 }
 
 #[derive(Clone)]
-pub(crate) struct GlyphedSection<'font> {
+pub(crate) struct GlyphedSection<'font, X = Extra> {
     pub bounds: Rect<f32>,
-    pub glyphs: Vec<(PositionedGlyph<'font>, Color, FontId)>,
-    pub z: f32,
+    pub glyphs: Vec<(PositionedGlyph<'font>, X, FontId)>,
 }
 
-impl<'font> GlyphedSection<'font> {
+impl<'font, X> GlyphedSection<'font, X> {
     pub(crate) fn pixel_bounds(&self) -> Option<Rect<i32>> {
         let Self {
             ref glyphs, bounds, ..
@@ -397,9 +598,83 @@ impl<'font> GlyphedSection<'font> {
         Some(pixel_bounds).filter(|_| !no_match)
     }
 
+    /// Returns the exact, unrounded pixel bounding box, see
+    /// [`GlyphCruncher::glyph_bounds`](trait.GlyphCruncher.html#method.glyph_bounds).
+    ///
+    /// Unlike [`pixel_bounds`](#method.pixel_bounds) this uses each glyph's exact
+    /// `exact_bounding_box` (offset by its position) rather than the whole-pixel
+    /// `pixel_bounding_box`, so the result retains sub-pixel precision.
+    pub(crate) fn glyph_bounds(&self) -> Option<Rect<f32>> {
+        let Self {
+            ref glyphs, bounds, ..
+        } = *self;
+
+        let inside_layout = |rect: Rect<f32>| {
+            if rect.max.x < bounds.min.x
+                || rect.max.y < bounds.min.y
+                || rect.min.x > bounds.max.x
+                || rect.min.y > bounds.max.y
+            {
+                return None;
+            }
+            Some(Rect {
+                min: Point {
+                    x: rect.min.x.max(bounds.min.x),
+                    y: rect.min.y.max(bounds.min.y),
+                },
+                max: Point {
+                    x: rect.max.x.min(bounds.max.x),
+                    y: rect.max.y.min(bounds.max.y),
+                },
+            })
+        };
+
+        let mut no_match = true;
+
+        let mut glyph_bounds = Rect {
+            min: point(0.0, 0.0),
+            max: point(0.0, 0.0),
+        };
+
+        for Rect { min, max } in glyphs
+            .iter()
+            .filter_map(|&(ref g, ..)| {
+                let bb = g.unpositioned().exact_bounding_box()?;
+                let pos = g.position();
+                Some(Rect {
+                    min: point(bb.min.x + pos.x, bb.min.y + pos.y),
+                    max: point(bb.max.x + pos.x, bb.max.y + pos.y),
+                })
+            })
+            .filter_map(inside_layout)
+        {
+            if no_match || min.x < glyph_bounds.min.x {
+                glyph_bounds.min.x = min.x;
+            }
+            if no_match || min.y < glyph_bounds.min.y {
+                glyph_bounds.min.y = min.y;
+            }
+            if no_match || max.x > glyph_bounds.max.x {
+                glyph_bounds.max.x = max.x;
+            }
+            if no_match || max.y > glyph_bounds.max.y {
+                glyph_bounds.max.y = max.y;
+            }
+            no_match = false;
+        }
+
+        Some(glyph_bounds).filter(|_| !no_match)
+    }
+
     pub(crate) fn glyphs(&self) -> PositionedGlyphIter<'_, 'font> {
         self.glyphs.iter().map(|(g, ..)| g)
     }
+
+    /// Returns an iterator over the positioned glyphs paired with their extra data, see
+    /// [`GlyphCruncher::glyphs_and_extras`](trait.GlyphCruncher.html#method.glyphs_and_extras).
+    pub(crate) fn glyphs_and_extras(&self) -> GlyphExtraIter<'_, 'font, X> {
+        self.glyphs.iter().map(|(g, x, _)| (g, x))
+    }
 }
 
 #[cfg(test)]
@@ -447,4 +722,154 @@ mod test {
             pixel_bounds.max.y
         );
     }
+
+    #[test]
+    fn glyph_bounds_respect_layout_bounds() {
+        let glyphs = GlyphCalculatorBuilder::using_font(A_FONT.clone()).build();
+        let mut glyphs = glyphs.cache_scope();
+
+        let section = Section {
+            text: "Hello\n\
+                   World",
+            screen_position: (0.0, 20.0),
+            bounds: (f32::INFINITY, 20.0),
+            scale: Scale::uniform(16.0),
+            layout: Layout::default().v_align(VerticalAlign::Bottom),
+            ..Section::default()
+        };
+
+        let glyph_bounds = glyphs.glyph_bounds(&section).expect("None bounds");
+        let layout_bounds = Layout::default()
+            .v_align(VerticalAlign::Bottom)
+            .bounds_rect(&section.into());
+
+        assert!(
+            layout_bounds.min.y <= glyph_bounds.min.y,
+            "expected {} <= {}",
+            layout_bounds.min.y,
+            glyph_bounds.min.y
+        );
+
+        assert!(
+            layout_bounds.max.y >= glyph_bounds.max.y,
+            "expected {} >= {}",
+            layout_bounds.max.y,
+            glyph_bounds.max.y
+        );
+    }
+
+    #[test]
+    fn glyph_bounds_has_sub_pixel_precision() {
+        let glyphs = GlyphCalculatorBuilder::using_font(A_FONT.clone()).build();
+        let mut glyphs = glyphs.cache_scope();
+
+        // a scale that will not land glyph metrics on exact whole-pixel boundaries
+        let section = Section {
+            text: "Hello World",
+            scale: Scale::uniform(17.3),
+            ..Section::default()
+        };
+
+        let pixel_bounds = glyphs.pixel_bounds(&section).expect("None pixel bounds");
+        let glyph_bounds = glyphs.glyph_bounds(&section).expect("None glyph bounds");
+
+        assert!(
+            glyph_bounds.min.x.fract() != 0.0
+                || glyph_bounds.min.y.fract() != 0.0
+                || glyph_bounds.max.x.fract() != 0.0
+                || glyph_bounds.max.y.fract() != 0.0,
+            "expected at least one sub-pixel bound, got {:?}",
+            glyph_bounds
+        );
+
+        // pixel_bounds is the conservative whole-pixel rect that contains glyph_bounds
+        assert!(
+            pixel_bounds.min.x as f32 <= glyph_bounds.min.x
+                && pixel_bounds.min.y as f32 <= glyph_bounds.min.y
+                && pixel_bounds.max.x as f32 >= glyph_bounds.max.x
+                && pixel_bounds.max.y as f32 >= glyph_bounds.max.y,
+            "expected {:?} to contain {:?}",
+            pixel_bounds,
+            glyph_bounds
+        );
+    }
+
+    #[test]
+    fn keep_cached_without_call_to_glyphs() {
+        let glyphs = GlyphCalculatorBuilder::using_font(A_FONT.clone()).build();
+
+        let section = Section {
+            text: "Hello World",
+            scale: Scale::uniform(16.0),
+            ..Section::default()
+        };
+
+        {
+            let mut scope = glyphs.cache_scope();
+            scope.keep_cached(&section);
+        }
+
+        assert_eq!(glyphs.calculate_glyph_cache.lock().unwrap().len(), 1);
+
+        {
+            let mut scope = glyphs.cache_scope();
+            scope.keep_cached(&section);
+        }
+
+        assert_eq!(glyphs.calculate_glyph_cache.lock().unwrap().len(), 1);
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct CustomExtra {
+        tag: u32,
+    }
+
+    impl From<Extra> for CustomExtra {
+        fn from(extra: Extra) -> Self {
+            CustomExtra {
+                tag: (extra.color[0] * 255.0) as u32,
+            }
+        }
+    }
+
+    #[test]
+    fn glyphs_and_extras_round_trips_custom_extra_type() {
+        let glyphs = GlyphCalculatorBuilder::using_font(A_FONT.clone())
+            .extra_type::<CustomExtra>()
+            .build();
+        let mut scope = glyphs.cache_scope();
+
+        let section = Section {
+            text: "Hello World",
+            scale: Scale::uniform(16.0),
+            color: [0.5, 0.25, 0.75, 1.0],
+            z: 0.3,
+            ..Section::default()
+        };
+
+        let expected = CustomExtra::from(Extra {
+            color: [0.5, 0.25, 0.75, 1.0],
+            z: 0.3,
+        });
+
+        let extras: Vec<_> = scope
+            .glyphs_and_extras(&section)
+            .map(|(_, extra)| extra.clone())
+            .collect();
+
+        assert!(!extras.is_empty());
+        assert!(extras.iter().all(|extra| *extra == expected));
+    }
+
+    #[test]
+    fn without_fonts_then_add_font() {
+        let mut builder = GlyphCalculatorBuilder::without_fonts();
+        assert_eq!(builder.font_data.len(), 0);
+
+        let font_id = builder.add_font(A_FONT.clone());
+        assert_eq!(font_id, FontId(0));
+
+        let glyphs = builder.build();
+        assert_eq!(glyphs.fonts().len(), 1);
+    }
 }